@@ -1,11 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::NaiveDate;
 use flate2::read::ZlibDecoder;
 use once_cell::sync::Lazy;
 
 use compact_calendar::CompactCalendar;
 
 use crate::localize::NoLocation;
+use crate::opening_hours::OpeningHours;
 
 /// An array of sorted holidays for each known region
 pub static REGION_HOLIDAYS: Lazy<HashMap<&str, CompactCalendar>> = Lazy::new(|| {
@@ -24,6 +26,26 @@ pub static REGION_HOLIDAYS: Lazy<HashMap<&str, CompactCalendar>> = Lazy::new(||
 
 pub const EMPTY_CALENDAR: &CompactCalendar = &CompactCalendar::new();
 
+/// How a [`DateOverride`] combines with the schedule that would otherwise
+/// apply on its date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DateOverrideMode {
+    /// The override's schedule fully replaces the base schedule.
+    Replace,
+    /// The override's schedule is unioned onto the base schedule, using
+    /// [`Schedule::addition`](crate::schedule::Schedule::addition).
+    Addition,
+}
+
+/// A single date-specific schedule override, as registered through
+/// [`OpeningHours::with_date_override`](crate::OpeningHours::with_date_override)
+/// or [`OpeningHours::with_date_addition`](crate::OpeningHours::with_date_addition).
+#[derive(Clone, Debug)]
+pub(crate) struct DateOverride {
+    pub(crate) hours: OpeningHours<NoLocation>,
+    pub(crate) mode: DateOverrideMode,
+}
+
 /// TODO: doc
 #[derive(Clone, Debug)]
 pub struct Context<L = NoLocation> {
@@ -31,6 +53,12 @@ pub struct Context<L = NoLocation> {
     pub holidays: &'static CompactCalendar,
     /// Localisation infos
     pub localize: L,
+    /// Per-date schedule overrides, keyed by the date they apply to. A
+    /// `BTreeMap` keeps this ordered so [`next_change_hint`] can find the
+    /// next registered override with a range lookup instead of a full scan.
+    ///
+    /// [`next_change_hint`]: crate::opening_hours::OpeningHours::next_change_hint
+    pub(crate) date_overrides: BTreeMap<NaiveDate, DateOverride>,
 }
 
 impl Default for Context<NoLocation> {
@@ -38,6 +66,7 @@ impl Default for Context<NoLocation> {
         Self {
             holidays: EMPTY_CALENDAR,
             localize: Default::default(),
+            date_overrides: BTreeMap::new(),
         }
     }
 }
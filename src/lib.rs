@@ -0,0 +1,20 @@
+mod context;
+mod localize;
+mod opening_hours;
+mod solar;
+mod utils;
+
+#[cfg(test)]
+mod tests {
+    mod agenda;
+    mod date_override;
+    mod html_week;
+    mod ical;
+    mod next_queries;
+    mod time_selector;
+}
+
+pub use crate::context::Context;
+pub use crate::localize::{CoordLocation, Localize, LocalizeWithTz, NoLocation, SolarEvent};
+pub use crate::opening_hours::{OpeningHours, DATE_LIMIT};
+pub use crate::utils::range::DateTimeRange;
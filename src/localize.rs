@@ -0,0 +1,168 @@
+//! Location/timezone state threaded through [`Context`](crate::context::Context).
+//!
+//! [`NoLocation`] resolves the `dawn`/`dusk`/`sunrise`/`sunset` time
+//! selector events to fixed fallback times; [`CoordLocation`] resolves them
+//! to real solar events computed by [`crate::solar`] for its coordinates.
+
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone};
+
+use opening_hours_syntax::extended_time::ExtendedTime;
+
+use crate::error::Result;
+use crate::solar;
+
+/// A solar event referenced by the time selector grammar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolarEvent {
+    Dawn,
+    Sunrise,
+    Sunset,
+    Dusk,
+}
+
+fn fallback_solar_event(event: SolarEvent) -> ExtendedTime {
+    match event {
+        SolarEvent::Dawn => ExtendedTime::new(6, 0),
+        SolarEvent::Sunrise => ExtendedTime::new(7, 0),
+        SolarEvent::Sunset => ExtendedTime::new(19, 0),
+        SolarEvent::Dusk => ExtendedTime::new(20, 0),
+    }
+}
+
+/// Location/timezone state carried by [`OpeningHours`](crate::OpeningHours).
+pub trait Localize: Clone {
+    type WithTz<Tz: TimeZone>: Localize;
+    type WithCoordInferTz: Localize;
+
+    fn with_tz<Tz: TimeZone>(self, tz: Tz) -> Self::WithTz<Tz>;
+    fn try_with_coord_infer_tz(self, lat: f64, lon: f64) -> Result<Self::WithCoordInferTz>;
+
+    /// Resolve a solar event for `date`. Locations with no known
+    /// coordinates fall back to fixed approximate times.
+    fn solar_event(&self, date: NaiveDate, event: SolarEvent) -> ExtendedTime {
+        let _ = date;
+        fallback_solar_event(event)
+    }
+
+    /// The UTC offset in effect at `at`, for locations with a known
+    /// timezone. `None` means instances of this type are floating.
+    fn utc_offset_at(&self, at: NaiveDateTime) -> Option<FixedOffset> {
+        let _ = at;
+        None
+    }
+}
+
+/// A [`Localize`] state to which coordinates can be given directly through
+/// `with_coord`. The resulting [`CoordLocation`] keeps no timezone.
+pub trait LocalizeWithTz: Localize {
+    type WithCoord: Localize;
+
+    fn with_coord(self, lat: f64, lon: f64) -> Self::WithCoord;
+}
+
+/// No location is known: `dawn`/`dusk`/`sunrise`/`sunset` resolve to fixed
+/// fallback times.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoLocation;
+
+impl Localize for NoLocation {
+    type WithTz<Tz: TimeZone> = TzLocation<Tz>;
+    type WithCoordInferTz = CoordLocation;
+
+    fn with_tz<Tz: TimeZone>(self, tz: Tz) -> Self::WithTz<Tz> {
+        TzLocation { tz }
+    }
+
+    fn try_with_coord_infer_tz(self, lat: f64, lon: f64) -> Result<Self::WithCoordInferTz> {
+        Ok(CoordLocation { lat, lon })
+    }
+}
+
+/// A known timezone, but no coordinates yet.
+#[derive(Clone, Debug)]
+pub struct TzLocation<Tz: TimeZone> {
+    pub(crate) tz: Tz,
+}
+
+impl<Tz: TimeZone> Localize for TzLocation<Tz> {
+    type WithTz<Tz2: TimeZone> = TzLocation<Tz2>;
+    type WithCoordInferTz = CoordLocation;
+
+    fn with_tz<Tz2: TimeZone>(self, tz: Tz2) -> Self::WithTz<Tz2> {
+        TzLocation { tz }
+    }
+
+    fn try_with_coord_infer_tz(self, lat: f64, lon: f64) -> Result<Self::WithCoordInferTz> {
+        Ok(CoordLocation { lat, lon })
+    }
+
+    fn utc_offset_at(&self, at: NaiveDateTime) -> Option<FixedOffset> {
+        Some(self.tz.offset_from_utc_datetime(&at).fix())
+    }
+}
+
+impl<Tz: TimeZone> LocalizeWithTz for TzLocation<Tz> {
+    type WithCoord = CoordLocation;
+
+    fn with_coord(self, lat: f64, lon: f64) -> Self::WithCoord {
+        CoordLocation { lat, lon }
+    }
+}
+
+/// A location known by coordinates, resolving real solar events via
+/// [`solar::solar_day`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CoordLocation {
+    pub(crate) lat: f64,
+    pub(crate) lon: f64,
+}
+
+impl Localize for CoordLocation {
+    type WithTz<Tz: TimeZone> = CoordLocation;
+    type WithCoordInferTz = CoordLocation;
+
+    fn with_tz<Tz: TimeZone>(self, _tz: Tz) -> Self::WithTz<Tz> {
+        self
+    }
+
+    fn try_with_coord_infer_tz(self, lat: f64, lon: f64) -> Result<Self::WithCoordInferTz> {
+        Ok(CoordLocation { lat, lon })
+    }
+
+    fn solar_event(&self, date: NaiveDate, event: SolarEvent) -> ExtendedTime {
+        let day = solar::solar_day(date, self.lat, self.lon);
+
+        // Sunrise/sunset and dawn/dusk use different zeniths (90.833° vs
+        // 96°), so at "white night" latitudes one pair can be polar day
+        // while the other still crosses normally: classify each
+        // independently instead of sharing a single flag.
+        let (minutes, is_polar_day) = match event {
+            SolarEvent::Dawn => (day.dawn_utc_min, day.civil_is_polar_day),
+            SolarEvent::Sunrise => (day.sunrise_utc_min, day.sun_is_polar_day),
+            SolarEvent::Sunset => (day.sunset_utc_min, day.sun_is_polar_day),
+            SolarEvent::Dusk => (day.dusk_utc_min, day.civil_is_polar_day),
+        };
+
+        match minutes {
+            Some(minutes) => solar::minutes_to_extended_time(minutes),
+            // Polar day/night: there is no sunrise/sunset, so treat the
+            // whole day as light or dark instead of panicking.
+            None if is_polar_day => match event {
+                SolarEvent::Dawn | SolarEvent::Sunrise => ExtendedTime::new(0, 0),
+                SolarEvent::Sunset | SolarEvent::Dusk => ExtendedTime::new(23, 59),
+            },
+            None => match event {
+                SolarEvent::Dawn | SolarEvent::Sunrise => ExtendedTime::new(23, 59),
+                SolarEvent::Sunset | SolarEvent::Dusk => ExtendedTime::new(0, 0),
+            },
+        }
+    }
+}
+
+impl LocalizeWithTz for CoordLocation {
+    type WithCoord = CoordLocation;
+
+    fn with_coord(self, lat: f64, lon: f64) -> Self::WithCoord {
+        CoordLocation { lat, lon }
+    }
+}
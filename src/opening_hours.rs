@@ -3,19 +3,21 @@ use std::cmp::{max, min};
 use std::convert::TryInto;
 use std::iter::{empty, Peekable};
 
-use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use once_cell::sync::Lazy;
 
 use compact_calendar::CompactCalendar;
 use opening_hours_syntax::extended_time::ExtendedTime;
 use opening_hours_syntax::rules::{RuleKind, RuleOperator, RuleSequence};
 
-use crate::context::{Context, REGION_HOLIDAYS};
+use crate::context::{Context, DateOverride, DateOverrideMode, REGION_HOLIDAYS};
 use crate::date_filter::DateFilter;
 use crate::error::{Error, Result};
 use crate::localize::{Localize, LocalizeWithTz, NoLocation};
 use crate::schedule::{Schedule, TimeRange};
 use crate::time_filter::{time_selector_intervals_at, time_selector_intervals_at_next_day};
+use crate::utils::html::escape_html;
+use crate::utils::ical::{escape_text, fold_line, offset_tzid, vtimezone_lines};
 use crate::DateTimeRange;
 
 /// The upper bound of dates handled by specification
@@ -28,7 +30,7 @@ pub static DATE_LIMIT: Lazy<NaiveDateTime> = Lazy::new(|| {
 
 // OpeningHours
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct OpeningHours<L = NoLocation> {
     /// Rules describing opening hours
     rules: Vec<RuleSequence>,
@@ -45,14 +47,19 @@ impl OpeningHours<NoLocation> {
         })
     }
 
-    // TODO: doc
+    /// Localize this schedule to the given coordinates. This resolves the
+    /// `dawn`/`dusk`/`sunrise`/`sunset` events used by the time selector to
+    /// the solar times computed for `lat`/`lon` on each evaluated date,
+    /// expressed as UTC times of day, instead of the fixed fallback times
+    /// used when no location is set. No timezone is inferred from the
+    /// coordinates: the result carries no UTC offset.
     #[cfg(feature = "localize")]
     pub fn try_with_coord(
         self,
         lat: f64,
         lon: f64,
     ) -> Result<OpeningHours<<NoLocation as Localize>::WithCoordInferTz>> {
-        todo!()
+        self.try_with_coord_infer_tz(lat, lon)
     }
 }
 
@@ -80,6 +87,37 @@ impl<L: Localize> OpeningHours<L> {
         Ok(self)
     }
 
+    /// Register a per-date override that fully replaces the schedule
+    /// otherwise produced by [`schedule_at`](Self::schedule_at) on `date`.
+    pub fn with_date_override(mut self, date: NaiveDate, hours: OpeningHours<NoLocation>) -> Self {
+        self.ctx
+            .date_overrides
+            .insert(date, DateOverride { hours, mode: DateOverrideMode::Replace });
+
+        self
+    }
+
+    /// Bulk variant of [`with_date_override`](Self::with_date_override).
+    pub fn with_date_overrides(
+        self,
+        overrides: impl IntoIterator<Item = (NaiveDate, OpeningHours<NoLocation>)>,
+    ) -> Self {
+        overrides
+            .into_iter()
+            .fold(self, |oh, (date, hours)| oh.with_date_override(date, hours))
+    }
+
+    /// Softer variant of [`with_date_override`](Self::with_date_override):
+    /// on `date`, `hours` is unioned onto the base schedule using
+    /// [`Schedule::addition`] instead of replacing it.
+    pub fn with_date_addition(mut self, date: NaiveDate, hours: OpeningHours<NoLocation>) -> Self {
+        self.ctx
+            .date_overrides
+            .insert(date, DateOverride { hours, mode: DateOverrideMode::Addition });
+
+        self
+    }
+
     // Low level implementations.
     //
     // Following functions are used to build the TimeDomainIterator which is
@@ -91,15 +129,38 @@ impl<L: Localize> OpeningHours<L> {
     /// Provide a lower bound to the next date when a different set of rules
     /// could match.
     fn next_change_hint(&self, date: NaiveDate) -> Option<NaiveDate> {
-        self.rules
+        let rules_hint = self
+            .rules
             .iter()
             .map(|rule| rule.day_selector.next_change_hint(date, self.holidays()))
             .min()
-            .flatten()
+            .flatten();
+
+        // A date override only applies to a single date, so the day right
+        // after one always needs to be re-evaluated, and the next
+        // registered override is itself a potential change point. The
+        // overrides are a `BTreeMap`, so both are single range lookups
+        // rather than a scan over every registered override.
+        let day_after_active_override =
+            date.succ_opt().filter(|_| self.ctx.date_overrides.contains_key(&date));
+
+        let next_registered_override = date
+            .succ_opt()
+            .and_then(|next| self.ctx.date_overrides.range(next..).next())
+            .map(|(&d, _)| d);
+
+        let override_hint = match (day_after_active_override, next_registered_override) {
+            (Some(a), Some(b)) => Some(min(a, b)),
+            (a, b) => a.or(b),
+        };
+
+        // No hint from the rules means they may change on the very next day,
+        // so an override further away must not extend the bound.
+        rules_hint.map(|a| override_hint.map_or(a, |b| min(a, b)))
     }
 
-    // TODO: doc
-    pub fn schedule_at(&self, date: NaiveDate) -> Schedule {
+    /// Evaluate the schedule for `date` without consulting date overrides.
+    fn schedule_at_without_overrides(&self, date: NaiveDate) -> Schedule {
         let mut prev_match = false;
         let mut prev_eval = None;
 
@@ -139,6 +200,22 @@ impl<L: Localize> OpeningHours<L> {
         prev_eval.unwrap_or_else(Schedule::empty)
     }
 
+    // TODO: doc
+    pub fn schedule_at(&self, date: NaiveDate) -> Schedule {
+        let Some(date_override) = self.ctx.date_overrides.get(&date) else {
+            return self.schedule_at_without_overrides(date);
+        };
+
+        let override_schedule = date_override.hours.schedule_at(date);
+
+        match date_override.mode {
+            DateOverrideMode::Replace => override_schedule,
+            DateOverrideMode::Addition => self
+                .schedule_at_without_overrides(date)
+                .addition(override_schedule),
+        }
+    }
+
     // TODO: doc
     pub fn iter_from(
         &self,
@@ -206,6 +283,47 @@ impl<L: Localize> OpeningHours<L> {
         )
     }
 
+    /// Find the start of the next interval of the given `kind` at or after
+    /// `t`, or `None` if none occurs before [`DATE_LIMIT`].
+    pub fn next_of_kind(
+        &self,
+        t: NaiveDateTime,
+        kind: RuleKind,
+    ) -> Result<Option<NaiveDateTime>> {
+        if t >= *DATE_LIMIT {
+            return Err(Error::DateLimitExceeded(t));
+        }
+
+        // Unlike `iter_from`, the raw iterator does not clip the interval in
+        // progress at `t` to start at `t`, so that interval only counts when
+        // it actually begins at `t`.
+        Ok(TimeDomainIterator::new(self, t, *DATE_LIMIT)
+            .find(|dtr| dtr.kind == kind && dtr.range.start >= t)
+            .map(|dtr| dtr.range.start))
+    }
+
+    /// Shorthand for [`next_of_kind`](Self::next_of_kind) with
+    /// [`RuleKind::Open`].
+    pub fn next_open(&self, t: NaiveDateTime) -> Result<Option<NaiveDateTime>> {
+        self.next_of_kind(t, RuleKind::Open)
+    }
+
+    /// Shorthand for [`next_of_kind`](Self::next_of_kind) with
+    /// [`RuleKind::Closed`].
+    pub fn next_closed(&self, t: NaiveDateTime) -> Result<Option<NaiveDateTime>> {
+        self.next_of_kind(t, RuleKind::Closed)
+    }
+
+    /// Yield at most `n` intervals starting from `from`, echoing how cron
+    /// schedules expose "the next N occurrences".
+    pub fn upcoming(
+        &self,
+        from: NaiveDateTime,
+        n: usize,
+    ) -> Result<impl Iterator<Item = DateTimeRange> + '_> {
+        Ok(self.iter_from(from)?.take(n))
+    }
+
     // TODO: doc
     pub fn intervals(
         &self,
@@ -221,6 +339,206 @@ impl<L: Localize> OpeningHours<L> {
                 DateTimeRange::new_with_sorted_comments(start..end, dtr.kind, dtr.comments)
             }))
     }
+
+    /// Yield one `(date, schedule)` entry per calendar day in `[from, to)`,
+    /// where `schedule` is the result of [`schedule_at`](Self::schedule_at)
+    /// for that day. Reuses the same schedule across an unchanged stretch
+    /// of days via [`next_change_hint`](Self::next_change_hint) instead of
+    /// recomputing it for each one.
+    pub fn iter_days(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> impl Iterator<Item = (NaiveDate, Schedule<'_>)> + '_ {
+        let mut curr_date = from;
+        let mut cached: Option<(NaiveDate, Schedule<'_>)> = None;
+
+        std::iter::from_fn(move || {
+            if curr_date >= to {
+                return None;
+            }
+
+            let date = curr_date;
+
+            let schedule = match &cached {
+                Some((valid_until, schedule)) if date < *valid_until => schedule.clone(),
+                _ => {
+                    let schedule = self.schedule_at(date);
+
+                    let valid_until = self
+                        .next_change_hint(date)
+                        .unwrap_or_else(|| date.succ_opt().expect("reached invalid date"));
+
+                    cached = Some((valid_until, schedule.clone()));
+                    schedule
+                }
+            };
+
+            curr_date = date.succ_opt().expect("reached invalid date");
+            Some((date, schedule))
+        })
+    }
+
+    /// Export the schedule evaluated between `from` and `to` as an RFC 5545
+    /// `VCALENDAR`, with one `VEVENT` per interval produced by
+    /// [`iter_range`](Self::iter_range). `SUMMARY` is derived from the
+    /// interval's [`RuleKind`], and the rule's `comments` are joined into
+    /// `DESCRIPTION` when present.
+    ///
+    /// `DTSTART`/`DTEND` carry a `TZID` parameter referencing a matching
+    /// `VTIMEZONE` component when this instance carries a timezone, as set by
+    /// [`with_tz`](Self::with_tz) on an instance without coordinates;
+    /// otherwise they are floating. Coordinate-localized instances carry no
+    /// timezone (`with_tz` leaves them unchanged and `with_coords` drops a
+    /// timezone set earlier), so their output is always floating.
+    pub fn to_ical(&self, from: NaiveDateTime, to: NaiveDateTime) -> Result<String> {
+        let dtrs: Vec<_> = self.iter_range(from, to)?.collect();
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//opening-hours-rs//EN".to_string(),
+        ];
+
+        let mut offsets = Vec::new();
+
+        for dtr in &dtrs {
+            for offset in [
+                self.ctx.localize.utc_offset_at(dtr.range.start),
+                self.ctx.localize.utc_offset_at(dtr.range.end),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                if !offsets.contains(&offset) {
+                    offsets.push(offset);
+                }
+            }
+        }
+
+        for offset in &offsets {
+            lines.extend(vtimezone_lines(*offset));
+        }
+
+        let dtstamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+
+        for (index, dtr) in dtrs.iter().enumerate() {
+            lines.push("BEGIN:VEVENT".to_string());
+
+            lines.push(format!(
+                "UID:{index}-{}@opening-hours-rs",
+                dtr.range.start.format("%Y%m%dT%H%M%S"),
+            ));
+
+            lines.push(format!("DTSTAMP:{dtstamp}"));
+
+            lines.push(ical_datetime_line(
+                "DTSTART",
+                dtr.range.start,
+                self.ctx.localize.utc_offset_at(dtr.range.start),
+            ));
+
+            lines.push(ical_datetime_line(
+                "DTEND",
+                dtr.range.end,
+                self.ctx.localize.utc_offset_at(dtr.range.end),
+            ));
+
+            lines.push(format!(
+                "SUMMARY:{}",
+                escape_text(match dtr.kind {
+                    RuleKind::Open => "Open",
+                    RuleKind::Closed => "Closed",
+                    RuleKind::Unknown => "Unknown",
+                })
+            ));
+
+            if !dtr.comments().is_empty() {
+                lines.push(format!("DESCRIPTION:{}", escape_text(&dtr.comments().join("\n"))));
+            }
+
+            lines.push("END:VEVENT".to_string());
+        }
+
+        lines.push("END:VCALENDAR".to_string());
+        Ok(lines.iter().map(|line| fold_line(line)).collect())
+    }
+
+    /// Render the week starting on `week_start` as a self-contained HTML
+    /// `<table>`, with one column per day and each open/closed/unknown span
+    /// in its own `<div>`, classed `oh-open`, `oh-closed` or `oh-unknown`
+    /// per [`RuleKind`] so callers can style the result. Rule `comments`
+    /// are surfaced as `title=` tooltips.
+    ///
+    /// Builds on [`iter_range`](Self::iter_range), splitting any interval
+    /// that crosses midnight across the two days it covers.
+    pub fn to_html_week(&self, week_start: NaiveDate) -> String {
+        let from = NaiveDateTime::new(week_start, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        let to = from + Duration::days(7);
+        let mut days: Vec<Vec<(NaiveDateTime, NaiveDateTime, RuleKind, String)>> =
+            (0..7).map(|_| Vec::new()).collect();
+
+        if let Ok(iter) = self.iter_range(from, to) {
+            for dtr in iter {
+                let mut start = dtr.range.start;
+                let comment = dtr.comments().join("; ");
+
+                while start < dtr.range.end {
+                    let next_midnight = NaiveDateTime::new(
+                        start.date().succ_opt().expect("reached invalid date"),
+                        NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+                    );
+
+                    let end = min(dtr.range.end, next_midnight);
+                    let day_index = (start.date() - week_start).num_days();
+
+                    if (0..7).contains(&day_index) {
+                        days[day_index as usize].push((start, end, dtr.kind, comment.clone()));
+                    }
+
+                    start = end;
+                }
+            }
+        }
+
+        let mut html = String::from("<table class=\"oh-week\">\n  <tr>\n");
+
+        for offset in 0..7 {
+            let date = week_start + Duration::days(offset);
+            html.push_str(&format!("    <th>{}</th>\n", date.format("%a %Y-%m-%d")));
+        }
+
+        html.push_str("  </tr>\n  <tr>\n");
+
+        for spans in &days {
+            html.push_str("    <td>\n");
+
+            for (start, end, kind, comment) in spans {
+                let class = match kind {
+                    RuleKind::Open => "oh-open",
+                    RuleKind::Closed => "oh-closed",
+                    RuleKind::Unknown => "oh-unknown",
+                };
+
+                let title = if comment.is_empty() {
+                    String::new()
+                } else {
+                    format!(" title=\"{}\"", escape_html(comment))
+                };
+
+                html.push_str(&format!(
+                    "      <div class=\"{class}\"{title}>{}\u{2013}{}</div>\n",
+                    start.format("%H:%M"),
+                    end.format("%H:%M"),
+                ));
+            }
+
+            html.push_str("    </td>\n");
+        }
+
+        html.push_str("  </tr>\n</table>\n");
+        html
+    }
 }
 
 impl<L: Localize> OpeningHours<L> {
@@ -232,6 +550,7 @@ impl<L: Localize> OpeningHours<L> {
             ctx: Context {
                 holidays: self.ctx.holidays,
                 localize: self.ctx.localize.with_tz(tz),
+                date_overrides: self.ctx.date_overrides,
             },
         }
     }
@@ -248,6 +567,7 @@ impl<L: Localize> OpeningHours<L> {
             ctx: Context {
                 holidays: self.ctx.holidays,
                 localize: self.ctx.localize.try_with_coord_infer_tz(lat, lon)?,
+                date_overrides: self.ctx.date_overrides,
             },
         })
     }
@@ -262,11 +582,24 @@ impl<L: LocalizeWithTz> OpeningHours<L> {
             ctx: Context {
                 holidays: self.ctx.holidays,
                 localize: self.ctx.localize.with_coord(lat, lon),
+                date_overrides: self.ctx.date_overrides,
             },
         }
     }
 }
 
+/// Format a single `DTSTART`/`DTEND` content line, adding a `TZID`
+/// parameter referencing the matching `VTIMEZONE` emitted by
+/// [`vtimezone_lines`] when `offset` is known.
+fn ical_datetime_line(name: &str, dt: NaiveDateTime, offset: Option<FixedOffset>) -> String {
+    match offset {
+        Some(offset) => {
+            format!("{name};TZID={}:{}", offset_tzid(offset), dt.format("%Y%m%dT%H%M%S"))
+        }
+        None => format!("{name}:{}", dt.format("%Y%m%dT%H%M%S")),
+    }
+}
+
 fn rule_sequence_schedule_at<'s, L: Localize>(
     ctx: &'s Context<L>,
     rule_sequence: &'s RuleSequence,
@@ -0,0 +1,203 @@
+//! Solar event computation (sunrise, sunset, civil dawn, civil dusk) using
+//! the NOAA sunrise/sunset algorithm. This provides the math that backs the
+//! `sunrise`, `sunset`, `dawn` and `dusk` events of coordinate-localized
+//! [`OpeningHours`](crate::OpeningHours) instances.
+
+use std::f64::consts::PI;
+
+use chrono::{Datelike, NaiveDate};
+
+use opening_hours_syntax::extended_time::ExtendedTime;
+
+/// Zenith angle (in degrees) used to resolve sunrise/sunset.
+const ZENITH_SUN: f64 = 90.833;
+/// Zenith angle (in degrees) used to resolve civil dawn/dusk.
+const ZENITH_CIVIL: f64 = 96.0;
+
+/// Solar events resolved for a single day, expressed as minutes from UTC
+/// midnight. `None` means the corresponding event never happens that day,
+/// which occurs during polar day or polar night at high latitudes.
+///
+/// Sunrise/sunset (zenith 90.833°) and dawn/dusk (zenith 96°) are
+/// classified independently: at "white night" latitudes it's common for
+/// the sun to rise and set normally while civil twilight never ends, so a
+/// single day can be polar day for one pair and not the other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SolarDay {
+    pub(crate) sunrise_utc_min: Option<f64>,
+    pub(crate) sunset_utc_min: Option<f64>,
+    pub(crate) dawn_utc_min: Option<f64>,
+    pub(crate) dusk_utc_min: Option<f64>,
+    /// Whether the sun never crosses the sunrise/sunset zenith this day
+    /// (polar day). If `false` and `sunrise`/`sunset` are `None`, the sun
+    /// never rises above it instead (polar night).
+    pub(crate) sun_is_polar_day: bool,
+    /// Same as `sun_is_polar_day`, but for the dawn/dusk (civil twilight)
+    /// zenith.
+    pub(crate) civil_is_polar_day: bool,
+}
+
+fn deg2rad(deg: f64) -> f64 {
+    deg * PI / 180.0
+}
+
+fn rad2deg(rad: f64) -> f64 {
+    rad * 180.0 / PI
+}
+
+/// Fractional year `γ` (radians), as used by the NOAA equations.
+fn fractional_year(date: NaiveDate) -> f64 {
+    let days_in_year = if date.leap_year() { 366.0 } else { 365.0 };
+    2.0 * PI / days_in_year * date.ordinal0() as f64
+}
+
+/// Equation of time, in minutes.
+fn equation_of_time(gamma: f64) -> f64 {
+    229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin())
+}
+
+/// Solar declination, in radians.
+fn solar_declination(gamma: f64) -> f64 {
+    0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos()
+        + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos()
+        + 0.00148 * (3.0 * gamma).sin()
+}
+
+/// Whether/where the sun crosses a given zenith on a given day at a given
+/// latitude.
+enum HourAngle {
+    /// The sun crosses the zenith; half-day length in degrees.
+    Crosses(f64),
+    /// The sun never goes below the zenith this day (polar day for it).
+    NeverBelow,
+    /// The sun never rises above the zenith this day (polar night for it).
+    NeverAbove,
+}
+
+fn hour_angle_deg(lat_rad: f64, decl: f64, zenith_deg: f64) -> HourAngle {
+    let cos_ha =
+        deg2rad(zenith_deg).cos() / (lat_rad.cos() * decl.cos()) - lat_rad.tan() * decl.tan();
+
+    if cos_ha < -1.0 {
+        HourAngle::NeverBelow
+    } else if cos_ha > 1.0 {
+        HourAngle::NeverAbove
+    } else {
+        HourAngle::Crosses(rad2deg(cos_ha.acos()))
+    }
+}
+
+/// Resolve all solar events for a single day at the given coordinates.
+pub(crate) fn solar_day(date: NaiveDate, lat_deg: f64, lon_deg: f64) -> SolarDay {
+    let gamma = fractional_year(date);
+    let eqtime = equation_of_time(gamma);
+    let decl = solar_declination(gamma);
+    let lat_rad = deg2rad(lat_deg);
+
+    // Returns `(rise, set, is_polar_day)` for a given zenith: `rise`/`set`
+    // are `None` together exactly when the sun never crosses that zenith,
+    // in which case `is_polar_day` tells polar day (sun stays above it)
+    // apart from polar night (sun stays below it).
+    let event = |zenith_deg: f64| match hour_angle_deg(lat_rad, decl, zenith_deg) {
+        HourAngle::Crosses(ha_deg) => (
+            Some(720.0 - 4.0 * (lon_deg + ha_deg) - eqtime),
+            Some(720.0 - 4.0 * (lon_deg - ha_deg) - eqtime),
+            false,
+        ),
+        HourAngle::NeverBelow => (None, None, true),
+        HourAngle::NeverAbove => (None, None, false),
+    };
+
+    let (sunrise_utc_min, sunset_utc_min, sun_is_polar_day) = event(ZENITH_SUN);
+    let (dawn_utc_min, dusk_utc_min, civil_is_polar_day) = event(ZENITH_CIVIL);
+
+    SolarDay {
+        sunrise_utc_min,
+        sunset_utc_min,
+        dawn_utc_min,
+        dusk_utc_min,
+        sun_is_polar_day,
+        civil_is_polar_day,
+    }
+}
+
+/// Convert a UTC-minutes-from-midnight value into an [`ExtendedTime`],
+/// clamped into the `[0, 1440)` domain. Values can fall well outside that
+/// range: at longitudes close to ±180°, `4 * lon_deg` alone can shift an
+/// event by several hours, rolling it onto the previous or next UTC day.
+/// [`Localize::solar_event`](crate::localize::Localize::solar_event) has no
+/// way to express "this event is actually on the adjacent day", so such
+/// values saturate at the day boundary instead of wrapping onto it.
+pub(crate) fn minutes_to_extended_time(minutes: f64) -> ExtendedTime {
+    const MINUTES_PER_DAY: f64 = 24.0 * 60.0;
+    let clamped = minutes.round().clamp(0.0, MINUTES_PER_DAY - 1.0) as u32;
+    ExtendedTime::new((clamped / 60) as u8, (clamped % 60) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equinox_paris_has_roughly_even_day() {
+        // 2024-03-20 is close to the spring equinox.
+        let day = solar_day(NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(), 48.85, 2.35);
+        let sunrise = day.sunrise_utc_min.expect("sun should rise at this latitude");
+        let sunset = day.sunset_utc_min.expect("sun should set at this latitude");
+        assert!(!day.sun_is_polar_day);
+        // Local solar noon in Paris (lon ~2.35°E) is a little before UTC
+        // noon; sunrise/sunset should straddle it roughly symmetrically.
+        assert!((sunset - sunrise - 12.0 * 60.0).abs() < 20.0);
+    }
+
+    #[test]
+    fn polar_night_at_north_pole_in_winter() {
+        let day = solar_day(NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(), 89.0, 0.0);
+        assert!(day.sunrise_utc_min.is_none());
+        assert!(day.sunset_utc_min.is_none());
+        assert!(!day.sun_is_polar_day);
+    }
+
+    #[test]
+    fn polar_day_at_north_pole_in_summer() {
+        let day = solar_day(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), 89.0, 0.0);
+        assert!(day.sunrise_utc_min.is_none());
+        assert!(day.sun_is_polar_day);
+    }
+
+    #[test]
+    fn white_night_has_sunrise_but_no_true_dawn() {
+        // Around the summer solstice at ~63°N (e.g. northern Scandinavia),
+        // the sun still rises and sets, but civil twilight never ends:
+        // sunrise/sunset should resolve while dawn/dusk are polar day.
+        let day = solar_day(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), 63.0, 0.0);
+        assert!(day.sunrise_utc_min.is_some());
+        assert!(day.sunset_utc_min.is_some());
+        assert!(!day.sun_is_polar_day);
+        assert!(day.dawn_utc_min.is_none());
+        assert!(day.dusk_utc_min.is_none());
+        assert!(day.civil_is_polar_day);
+    }
+
+    #[test]
+    fn clamps_out_of_range_minutes() {
+        assert_eq!(minutes_to_extended_time(-5.0), ExtendedTime::new(0, 0));
+        assert_eq!(minutes_to_extended_time(1445.0), ExtendedTime::new(23, 59));
+        assert_eq!(minutes_to_extended_time(30.0), ExtendedTime::new(0, 30));
+    }
+
+    #[test]
+    fn clamps_large_offsets_at_extreme_longitudes() {
+        // Near lon = +180°, `4 * lon_deg` alone is 720 minutes, enough to
+        // push a raw value far outside `[0, 1440)`; it should saturate at
+        // the day boundary rather than wrap onto the adjacent day.
+        assert_eq!(minutes_to_extended_time(-300.0), ExtendedTime::new(0, 0));
+        assert_eq!(minutes_to_extended_time(1800.0), ExtendedTime::new(23, 59));
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::error::Result;
+use crate::OpeningHours;
+
+#[test]
+fn iter_days_matches_schedule_at_for_every_day() -> Result<()> {
+    let oh = OpeningHours::parse("Mo-Fr 09:00-18:00")?;
+    let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(); // a Monday
+    let to = from + Duration::days(14);
+
+    let days: Vec<_> = oh.iter_days(from, to).collect();
+    assert_eq!(days.len(), 14);
+
+    for (date, schedule) in days {
+        assert_eq!(schedule, oh.schedule_at(date));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use opening_hours_syntax::rules::RuleKind;
+
+use crate::error::Result;
+use crate::OpeningHours;
+
+fn at(date: NaiveDate, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDateTime::new(date, NaiveTime::from_hms_opt(hour, minute, 0).unwrap())
+}
+
+#[test]
+fn replace_override_ignores_base_schedule() -> Result<()> {
+    let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+
+    let oh = OpeningHours::parse("Mo-Su 09:00-18:00")?
+        .with_date_override(christmas, OpeningHours::parse("off")?);
+
+    assert_eq!(oh.state(at(christmas, 10, 0))?, RuleKind::Closed);
+    assert_eq!(oh.state(at(christmas.pred_opt().unwrap(), 10, 0))?, RuleKind::Open);
+
+    Ok(())
+}
+
+#[test]
+fn addition_override_unions_onto_base_schedule() -> Result<()> {
+    let special_day = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+
+    let oh = OpeningHours::parse("Mo-Su 09:00-12:00")?
+        .with_date_addition(special_day, OpeningHours::parse("18:00-20:00")?);
+
+    // The base schedule still applies in the morning...
+    assert_eq!(oh.state(at(special_day, 10, 0))?, RuleKind::Open);
+    // ...and the addition opens up the evening on top of it.
+    assert_eq!(oh.state(at(special_day, 19, 0))?, RuleKind::Open);
+    // Hours not covered by either stay closed.
+    assert_eq!(oh.state(at(special_day, 14, 0))?, RuleKind::Closed);
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use chrono::NaiveDate;
+
+use crate::error::Result;
+use crate::OpeningHours;
+
+#[test]
+fn to_html_week_renders_one_column_per_day() -> Result<()> {
+    let oh = OpeningHours::parse("Mo 09:00-12:00")?;
+    let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+    let html = oh.to_html_week(monday);
+    assert_eq!(html.matches("<th>").count(), 7);
+    assert!(html.contains("oh-open"));
+    assert!(html.contains("09:00"));
+    assert!(html.contains("12:00"));
+
+    Ok(())
+}
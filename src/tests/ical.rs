@@ -0,0 +1,47 @@
+use chrono::{Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::error::Result;
+use crate::OpeningHours;
+
+fn week_from(date: NaiveDate) -> (NaiveDateTime, NaiveDateTime) {
+    let from = NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+    (from, from + Duration::days(7))
+}
+
+#[test]
+fn to_ical_is_floating_without_timezone() -> Result<()> {
+    let oh = OpeningHours::parse("Mo 09:00-12:00")?;
+    let (from, to) = week_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+
+    let ical = oh.to_ical(from, to)?;
+    assert!(ical.contains("DTSTART:20240101T090000"));
+    assert!(!ical.contains("TZID"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "localize")]
+fn to_ical_emits_tzid_when_localized() -> Result<()> {
+    let tz = FixedOffset::east_opt(3600).unwrap();
+    let oh = OpeningHours::parse("Mo 09:00-12:00")?.with_tz(tz);
+    let (from, to) = week_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+
+    let ical = oh.to_ical(from, to)?;
+    let tzid = "opening-hours-rs/UTC+01:00";
+    assert!(ical.contains(&format!("DTSTART;TZID={tzid}:20240101T090000")));
+    assert!(ical.contains(&format!("DTEND;TZID={tzid}:20240101T120000")));
+
+    // A TZID must resolve to a VTIMEZONE component in the same object.
+    assert!(ical.contains("BEGIN:VTIMEZONE"));
+    assert!(ical.contains(&format!("TZID:{tzid}")));
+    assert!(ical.contains("TZOFFSETFROM:+0100"));
+    assert!(ical.contains("TZOFFSETTO:+0100"));
+
+    // VTIMEZONE must be defined before any VEVENT that references it.
+    let vtimezone_pos = ical.find("BEGIN:VTIMEZONE").unwrap();
+    let vevent_pos = ical.find("BEGIN:VEVENT").unwrap();
+    assert!(vtimezone_pos < vevent_pos);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use opening_hours_syntax::rules::RuleKind;
+
+use crate::error::Result;
+use crate::OpeningHours;
+
+fn dt(date: NaiveDate, h: u32, m: u32) -> NaiveDateTime {
+    NaiveDateTime::new(date, NaiveTime::from_hms_opt(h, m, 0).unwrap())
+}
+
+#[test]
+fn next_of_kind_finds_the_right_boundary() -> Result<()> {
+    let oh = OpeningHours::parse("Mo 09:00-12:00")?;
+    let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+    let before_open = dt(monday, 8, 0);
+    assert_eq!(oh.next_of_kind(before_open, RuleKind::Open)?, Some(dt(monday, 9, 0)));
+    assert_eq!(oh.next_of_kind(before_open, RuleKind::Closed)?, Some(dt(monday, 12, 0)));
+
+    assert_eq!(oh.next_open(before_open)?, Some(dt(monday, 9, 0)));
+    assert_eq!(oh.next_closed(before_open)?, Some(dt(monday, 12, 0)));
+
+    Ok(())
+}
+
+#[test]
+fn upcoming_yields_at_most_n_intervals_in_order() -> Result<()> {
+    let oh = OpeningHours::parse("Mo-Fr 09:00-12:00")?;
+    let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+    let ranges: Vec<_> = oh.upcoming(dt(monday, 0, 0), 3)?.collect();
+    assert_eq!(ranges.len(), 3);
+
+    for pair in ranges.windows(2) {
+        assert!(pair[0].range.start < pair[1].range.start);
+    }
+
+    Ok(())
+}
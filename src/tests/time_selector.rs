@@ -69,3 +69,29 @@ fn overlap() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "localize")]
+fn coord_localized_events_differ_from_fallback() -> Result<()> {
+    use chrono::NaiveDate;
+    use opening_hours_syntax::extended_time::ExtendedTime;
+
+    use crate::localize::{CoordLocation, Localize, SolarEvent};
+    use crate::OpeningHours;
+
+    // Exercise the try_with_coord chain end-to-end.
+    OpeningHours::parse("24/7")?.try_with_coord(48.85, 2.35)?;
+
+    // Paris, near the 2024 spring equinox: the real sunrise/sunset should
+    // be well away from the NoLocation fallback of 07:00/19:00.
+    let paris = CoordLocation { lat: 48.85, lon: 2.35 };
+    let date = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap();
+    let sunrise = paris.solar_event(date, SolarEvent::Sunrise);
+    let sunset = paris.solar_event(date, SolarEvent::Sunset);
+
+    assert_ne!(sunrise, ExtendedTime::new(7, 0));
+    assert_ne!(sunset, ExtendedTime::new(19, 0));
+    assert!(sunrise < sunset);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Tiny HTML escaping helper, used by
+//! [`OpeningHours::to_html_week`](crate::OpeningHours::to_html_week).
+
+/// Escape the characters that are unsafe to place inside an HTML attribute
+/// value or text node: `&`, `<`, `>` and `"`.
+pub(crate) fn escape_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
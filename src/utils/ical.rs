@@ -0,0 +1,81 @@
+//! Small helpers for emitting RFC 5545 (iCalendar) content, used by
+//! [`OpeningHours::to_ical`](crate::OpeningHours::to_ical).
+
+use chrono::FixedOffset;
+
+/// The `TZID` used to reference a fixed UTC offset, paired with a
+/// `VTIMEZONE` definition from [`vtimezone_lines`] so it resolves to
+/// something per RFC 5545 §3.6.5 instead of a made-up, unregistered name.
+pub(crate) fn offset_tzid(offset: FixedOffset) -> String {
+    format!("opening-hours-rs/UTC{offset}")
+}
+
+/// Emit a self-contained `VTIMEZONE` component for a fixed UTC offset,
+/// under the `TZID` produced by [`offset_tzid`] for the same offset.
+pub(crate) fn vtimezone_lines(offset: FixedOffset) -> Vec<String> {
+    let total_minutes = offset.local_minus_utc() / 60;
+    let sign = if total_minutes < 0 { '-' } else { '+' };
+    let total_minutes = total_minutes.abs();
+    let hhmm = format!("{sign}{:02}{:02}", total_minutes / 60, total_minutes % 60);
+
+    vec![
+        "BEGIN:VTIMEZONE".to_string(),
+        format!("TZID:{}", offset_tzid(offset)),
+        "BEGIN:STANDARD".to_string(),
+        "DTSTART:19700101T000000".to_string(),
+        format!("TZOFFSETFROM:{hhmm}"),
+        format!("TZOFFSETTO:{hhmm}"),
+        format!("TZNAME:UTC{offset}"),
+        "END:STANDARD".to_string(),
+        "END:VTIMEZONE".to_string(),
+    ]
+}
+
+/// Escape the characters that RFC 5545 §3.3.11 requires to be escaped in a
+/// `TEXT` value: `\`, `;`, `,` and newlines (turned into a literal `\n`).
+pub(crate) fn escape_text(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Fold a single logical content line into one or more physical lines of at
+/// most 75 octets, as required by RFC 5545 §3.1. Continuation lines start
+/// with a single space and every physical line is terminated with CRLF.
+pub(crate) fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+
+    let mut out = String::new();
+    let mut rest = line;
+    let mut first = true;
+
+    while !rest.is_empty() || first {
+        let budget = if first { LIMIT } else { LIMIT - 1 };
+        let mut len = rest.len().min(budget);
+
+        while !rest.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        if !first {
+            out.push(' ');
+        }
+
+        out.push_str(&rest[..len]);
+        out.push_str("\r\n");
+        rest = &rest[len..];
+        first = false;
+    }
+
+    out
+}